@@ -0,0 +1,101 @@
+//! A guided, misuse-resistant way to construct relocatable graphs of
+//! `Rel`/`RelBox`/`RelRef` fields that point at each other or at sibling
+//! fields of the same struct, using `Rel::init` to populate each field
+//! once it is already pinned at its final address.
+
+#[cfg(feature = "std")]
+use std as alloc;
+
+use alloc::boxed::Box;
+use core::mem::MaybeUninit;
+use core::pin::Pin;
+
+/// Pins a `T` at a fixed heap location before any of its fields are
+/// written, so that `Rel::init` can be used on its `Rel`-typed fields
+/// (including ones pointing at other fields of the same `T`) without
+/// ever having to move the value afterwards.
+pub struct RelBuilder<T> {
+    value: Pin<Box<MaybeUninit<T>>>,
+}
+
+impl<T> RelBuilder<T> {
+    /// Start building a new `T` at a fixed heap location.
+    pub fn new() -> Self {
+        Self {
+            value: Box::pin(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Get pinned, mutable access to the not-yet-initialized value so
+    /// its fields can be written in place. Because the value is
+    /// `Pin`ned, it's guaranteed to stay at the same address between
+    /// calls, keeping any `Rel::init` calls on its fields valid.
+    pub fn as_mut(&mut self) -> Pin<&mut MaybeUninit<T>> {
+        self.value.as_mut()
+    }
+
+    /// Projects a single not-yet-initialized field out of the pinned
+    /// value, ready to hand to `Rel::init`/`RelSlice::init`. `field`
+    /// should build its pointer with `core::ptr::addr_of_mut!`, e.g.
+    /// `|p| core::ptr::addr_of_mut!((*p).some_field)`.
+    ///
+    /// # Safety
+    /// `field` must return a pointer to a field that actually lives
+    /// inside the `T` this builder owns; returning a pointer to
+    /// unrelated memory is undefined behavior.
+    pub unsafe fn field<U>(&mut self, field: impl FnOnce(*mut T) -> *mut U) -> &mut MaybeUninit<U> {
+        let ptr = field(self.value.as_mut().get_unchecked_mut().as_mut_ptr());
+        &mut *(ptr as *mut MaybeUninit<U>)
+    }
+
+    /// Finish construction, asserting that every field of `T` has been
+    /// initialized (e.g. via `Rel::init` or plain field writes through
+    /// `as_mut`).
+    ///
+    /// # Safety
+    /// The caller must have fully initialized the pinned value before
+    /// calling this.
+    pub unsafe fn assume_init(self) -> Pin<Box<T>> {
+        let raw = Box::into_raw(Pin::into_inner_unchecked(self.value));
+        Pin::new_unchecked(Box::from_raw(raw as *mut T))
+    }
+}
+
+impl<T> Default for RelBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rel;
+
+    #[test]
+    fn builds_struct_with_field_pointing_at_a_sibling() {
+        struct Pair {
+            value: i32,
+            link: Rel<*const i32>,
+        }
+
+        let mut builder = RelBuilder::<Pair>::new();
+
+        let value_ptr = unsafe {
+            let slot = builder.field(|p| core::ptr::addr_of_mut!((*p).value));
+            let ptr = slot.as_mut_ptr();
+            ptr.write(42);
+            ptr as *const i32
+        };
+
+        unsafe {
+            let link_slot = builder.field(|p| core::ptr::addr_of_mut!((*p).link));
+            Rel::init(link_slot, value_ptr).unwrap();
+        }
+
+        let pair = unsafe { builder.assume_init() };
+
+        assert_eq!(pair.value, 42);
+        assert_eq!(unsafe { *pair.link.get_raw() }, 42);
+    }
+}
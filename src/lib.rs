@@ -6,6 +6,7 @@
         ptr_wrapping_offset_from,
         box_into_raw_non_null,
         rc_into_raw_non_null,
+        ptr_metadata,
     )
 )]
 
@@ -22,13 +23,78 @@ compile_error!("Please select only 1 of std or alloc.");
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::marker::{PhantomData, PhantomPinned};
+use core::mem::MaybeUninit;
 use core::ops::{Deref, DerefMut};
+#[cfg(feature = "nightly")]
+use core::ptr::Pointee;
 use num_traits::PrimInt;
 
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod builder;
+pub mod slice;
 pub mod traits;
 
-use traits::{Pointer, PointerMut, PointerNonNull, PointerNullable};
+pub use slice::{RelSlice, RelSliceMut};
+use traits::{Pointer, PointerMut, PointerNonNull};
+
+/// Sealed integer types usable as `Rel`'s offset, paired with the
+/// `core::num::NonZero*` type that stores their nonzero values. `Rel`
+/// stores its offset this way (see `Rel::bias`) so that `offset` can
+/// never be the all-zero bit pattern, which frees that pattern up for
+/// `Option<Rel<P, I>>` to use as its own niche. Sealed so the mapping
+/// can't drift out of sync with the integer types this crate supports.
+pub trait NicheInt: PrimInt + niche::Sealed {
+    /// The `core::num::NonZero*` counterpart of this integer type.
+    type NonZero: Copy;
+
+    /// Wraps a value known to be nonzero. Only ever called on deltas
+    /// that already went through `Rel::bias`, which never produces 0.
+    fn to_nonzero(self) -> Option<Self::NonZero>;
+
+    /// Unwraps back to the plain integer.
+    fn from_nonzero(n: Self::NonZero) -> Self;
+}
+
+mod niche {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_niche_int {
+    ($($int:ty => $nonzero:ty),+ $(,)?) => {
+        $(
+            impl niche::Sealed for $int {}
+            impl NicheInt for $int {
+                type NonZero = $nonzero;
+
+                fn to_nonzero(self) -> Option<Self::NonZero> {
+                    <$nonzero>::new(self)
+                }
+                fn from_nonzero(n: Self::NonZero) -> Self {
+                    n.get()
+                }
+            }
+        )+
+    };
+}
+
+impl_niche_int! {
+    i8 => core::num::NonZeroI8,
+    i16 => core::num::NonZeroI16,
+    i32 => core::num::NonZeroI32,
+    i64 => core::num::NonZeroI64,
+    i128 => core::num::NonZeroI128,
+    isize => core::num::NonZeroIsize,
+    u8 => core::num::NonZeroU8,
+    u16 => core::num::NonZeroU16,
+    u32 => core::num::NonZeroU32,
+    u64 => core::num::NonZeroU64,
+    u128 => core::num::NonZeroU128,
+    usize => core::num::NonZeroUsize,
+}
 
 /// The core of this crate. Rel wraps an existing smart (or raw)
 /// pointer and store the offset to its target rather than the
@@ -36,13 +102,38 @@ use traits::{Pointer, PointerMut, PointerNonNull, PointerNullable};
 /// the value also moves the destination of the pointer.
 /// It is safe to move this if it's pointing into the same block
 /// of memory as the one its target is located in.
-pub struct Rel<P: Pointer, I: PrimInt=isize> {
-    offset: I,
+///
+/// With the `nightly` feature, `P::Target` may be `?Sized`, in which case
+/// the pointer metadata (e.g. a slice/str length) is stored alongside the
+/// offset. Only metadata that stays meaningful after the pointee moves is
+/// supported this way; `dyn Trait` vtable pointers are absolute addresses
+/// and must not be stored here.
+///
+/// `offset` is backed by a `NicheInt::NonZero` rather than a plain `I`:
+/// every real target, including one at `Rel`'s own address (see
+/// `bias`/`unbias`), is stored as a nonzero value, so `offset` never
+/// needs the all-zero bit pattern. That spare pattern is what lets
+/// `Option<Rel<P, I>>` be the same size as `Rel<P, I>` — the same niche
+/// optimization `Option<NonNull<T>>` gets in std. `Rel` itself has no
+/// null state of its own; use `Option<Rel<P, I>>`'s `None` wherever a
+/// `Rel` needs to be absent.
+///
+/// This is a breaking change from earlier versions: `Rel::new`,
+/// `Rel::take`, `Rel`'s `Default` impl, and the `traits::PointerNullable`
+/// trait that backed them are all gone. Callers that relied on a `Rel`
+/// being constructible empty or resettable to empty should switch to
+/// `Option<Rel<P, I>>` instead.
+pub struct Rel<P: Pointer, I: NicheInt=isize> {
+    offset: I::NonZero,
+    #[cfg(feature = "nightly")]
+    metadata: <P::Target as Pointee>::Metadata,
     _pd: PhantomData<P>,
     _pp: PhantomPinned,
 }
 
 /// A relative immutible reference. Does not own its contents.
+/// With the `nightly` feature this also supports unsized targets,
+/// e.g. `RelRef<[T]>` or `RelRef<str>`.
 pub type RelRef<'a, T, I=isize> = Rel<&'a T, I>;
 
 /// A relative mutible reference. Possesses the same permissions
@@ -52,6 +143,8 @@ pub type RelMut<'a, T, I=isize> = Rel<&'a mut T, I>;
 #[cfg(any(feature = "alloc", feature = "std"))]
 /// A relative owned pointer. Behaves like a `Box`
 /// when determining ownership.
+/// With the `nightly` feature this also supports unsized targets,
+/// e.g. `RelBox<[T]>` or `RelBox<str>`.
 pub type RelBox<T, I=isize> = Rel<Box<T>, I>;
 
 mod err {
@@ -90,18 +183,31 @@ mod err {
 
 pub use err::OutOfRange;
 
-impl<P: Pointer, I: PrimInt> Rel<P, I> {
+impl<P: Pointer, I: NicheInt> Rel<P, I> {
     /// Initializes the relative pointer with a provided pointer.
     /// Assumes that the relative pointer is not yet initialized
     /// and will leak if it already contains a value.
     pub unsafe fn set_raw(this: *mut Self, p: P) -> Result<(), OutOfRange<I>> {
         let p = p.into_raw();
+        #[cfg(feature = "nightly")]
+        let metadata = core::ptr::metadata(p);
         let offset = Self::offset_to(this, p)?;
-        *this = Self {
-            offset,
-            _pd: PhantomData,
-            _pp: PhantomPinned,
-        };
+        // `*this = Self { .. }` would first run `drop_in_place(this)` on
+        // whatever bytes are already there — garbage for a fresh
+        // `MaybeUninit` slot, and a premature second drop for a slot
+        // `replace`/`swap`/`clone_into` already extracted the old
+        // value out of. `ptr::write` never reads or drops the old
+        // contents, it just overwrites them.
+        core::ptr::write(
+            this,
+            Self {
+                offset,
+                #[cfg(feature = "nightly")]
+                metadata,
+                _pd: PhantomData,
+                _pp: PhantomPinned,
+            },
+        );
         Ok(())
     }
 
@@ -113,29 +219,138 @@ impl<P: Pointer, I: PrimInt> Rel<P, I> {
         (inner, unsafe { Self::set_raw(self, p) })
     }
 
-    fn offset_to(this: *const Self, target: *const P::Target) -> Result<I, OutOfRange<I>> {
+    /// Safely initializes a `Rel` that is already sitting at its final
+    /// memory location, wrapped in `MaybeUninit` (e.g. a field reached
+    /// through a `builder::RelBuilder`, or any other slot that is
+    /// guaranteed not to move afterwards). This is `set_raw` without the
+    /// `unsafe`: writing into `MaybeUninit` can't clobber a live value,
+    /// and since `this` is already where it will stay, the offset
+    /// computed against its address remains correct.
+    pub fn init(this: &mut MaybeUninit<Self>, p: P) -> Result<&mut Self, OutOfRange<I>> {
+        unsafe {
+            Self::set_raw(this.as_mut_ptr(), p)?;
+            Ok(this.assume_init_mut())
+        }
+    }
+
+    /// Swaps the targets of `self` and `other`, without touching the
+    /// pointed-to data. Since each stored offset is relative to the
+    /// location of its own `Rel`, a bitwise swap of the `offset` fields
+    /// would be wrong; both offsets are instead recomputed against
+    /// their (unmoved) locations. If re-initializing either side goes
+    /// out of range, both pointers are restored to their original
+    /// targets before the error is returned, so neither is left
+    /// dangling or double-freed.
+    ///
+    /// This bookkeeping relies on `set_raw` overwriting `self`/`other`
+    /// without dropping what was there before — `self_ptr`/`other_ptr`
+    /// are owning handles this function is still responsible for, and
+    /// each `set_raw` call below targets a slot that's already had its
+    /// prior value extracted via `get_raw`/`from_raw`, not a fresh one.
+    pub fn swap(&mut self, other: &mut Self) -> Result<(), OutOfRange<I>> {
+        let self_raw = self.get_raw();
+        let other_raw = other.get_raw();
+
+        let self_ptr = unsafe { P::from_raw(self_raw) };
+        let other_ptr = unsafe { P::from_raw(other_raw) };
+
+        if let Err(e) = unsafe { Self::set_raw(self, other_ptr) } {
+            // `self` was never written to by the failed call above, so
+            // both floating handles just need to find their way back to
+            // the slot they came from.
+            unsafe { Self::set_raw(self, self_ptr) }.ok();
+            let other_ptr = unsafe { P::from_raw(other_raw) };
+            unsafe { Self::set_raw(other, other_ptr) }.ok();
+            return Err(e);
+        }
+
+        if let Err(e) = unsafe { Self::set_raw(other, self_ptr) } {
+            // Unlike the branch above, the call that just failed never
+            // touched `other` — it still correctly encodes `other_raw`.
+            // `self`, however, was already overwritten by the first call
+            // to encode `other_raw` too. Undoing that is the only repair
+            // needed here: restoring `other_raw` into `other` as well
+            // would leave both slots owning the same target, which gets
+            // freed twice once they're dropped.
+            let self_ptr = unsafe { P::from_raw(self_raw) };
+            unsafe { Self::set_raw(self, self_ptr) }.ok();
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    /// Computes the raw, unbiased byte delta from `this` to `target`'s
+    /// data address, with no regard for the null/self-pointer niche.
+    fn delta_to(this: *const Self, target: *const P::Target) -> isize {
         let from = this as *const u8;
-        let to = target as *const u8;
+        // Only the data-address half of `target` is relative; any
+        // metadata (slice/str length) is stored separately and stays
+        // valid after the pointee moves.
+        let to = target as *const () as *const u8;
+
+        #[cfg(feature = "nightly")]
+        {
+            to.wrapping_offset_from(from)
+        }
+        #[cfg(not(feature = "nightly"))]
+        {
+            (to as isize).wrapping_sub(from as isize)
+        }
+    }
 
-        let offset = {
-            #[cfg(feature = "nightly")]
-            {
-                to.wrapping_offset_from(from)
-            }
-            #[cfg(not(feature = "nightly"))]
-            {
-                (to as isize).wrapping_sub(from as isize)
-            }
-        };
-        I::from(offset).ok_or_else(|| OutOfRange::new(offset))
+    /// `offset` is backed by a `NonZero` integer (see `NicheInt`), so the
+    /// stored value can never be 0 — but a target at delta 0 (a `Rel`
+    /// pointing at its own location) is exactly that value. Every
+    /// non-negative delta is therefore biased up by one before being
+    /// stored; negative deltas need no bias, since they can never
+    /// collide with 0.
+    fn bias(delta: isize) -> Option<isize> {
+        if delta >= 0 {
+            delta.checked_add(1)
+        } else {
+            Some(delta)
+        }
+    }
+
+    /// Inverse of `bias`: recovers the real delta from a stored,
+    /// non-zero offset.
+    fn unbias(stored: isize) -> isize {
+        if stored > 0 {
+            stored - 1
+        } else {
+            stored
+        }
+    }
+
+    fn offset_to(this: *const Self, target: *const P::Target) -> Result<I::NonZero, OutOfRange<I>> {
+        let delta = Self::delta_to(this, target);
+        let biased = Self::bias(delta).ok_or_else(|| OutOfRange::new(delta))?;
+        let biased = I::from(biased).ok_or_else(|| OutOfRange::new(delta))?;
+        Ok(biased
+            .to_nonzero()
+            .expect("bias() never produces a value that maps to zero"))
     }
 
     /// Acquire a raw pointer to the target
     /// This can be passed to `Pointer::from_raw`
     /// to reconstruct the original smart pointer.
+    #[cfg(feature = "nightly")]
     pub fn get_raw(&self) -> *const P::Target {
-        let offset = self.offset.to_isize().unwrap();
-        (self as *const _ as *const u8).wrapping_offset(offset) as *const P::Target
+        let stored = I::from_nonzero(self.offset).to_isize().unwrap();
+        let delta = Self::unbias(stored);
+        let data = (self as *const _ as *const u8).wrapping_offset(delta) as *const ();
+        core::ptr::from_raw_parts(data, self.metadata)
+    }
+
+    /// Acquire a raw pointer to the target
+    /// This can be passed to `Pointer::from_raw`
+    /// to reconstruct the original smart pointer.
+    #[cfg(not(feature = "nightly"))]
+    pub fn get_raw(&self) -> *const P::Target {
+        let stored = I::from_nonzero(self.offset).to_isize().unwrap();
+        let delta = Self::unbias(stored);
+        (self as *const _ as *const u8).wrapping_offset(delta) as *const P::Target
     }
 
     fn with_inner<T>(&self, f: impl FnOnce(&P) -> T) -> T {
@@ -146,7 +361,7 @@ impl<P: Pointer, I: PrimInt> Rel<P, I> {
     }
 }
 
-impl<P: PointerMut, I: PrimInt> Rel<P, I> {
+impl<P: PointerMut, I: NicheInt> Rel<P, I> {
     /// Acquire a raw mutible pointer to the target
     /// This can be passed to `PointerMut::from_raw`
     /// to reconstruct the original smart pointer.
@@ -155,7 +370,7 @@ impl<P: PointerMut, I: PrimInt> Rel<P, I> {
     }
 }
 
-impl<P: Pointer + Clone, I: PrimInt> Rel<P, I> {
+impl<P: Pointer + Clone, I: NicheInt> Rel<P, I> {
     /// Clones the value in this pointer into `target`.
     /// May or may not clone the target value or just the pointer
     /// depending on which type this was initialized as.
@@ -169,68 +384,251 @@ impl<P: Pointer + Clone, I: PrimInt> Rel<P, I> {
     pub unsafe fn clone_from_raw(this: *mut Self, source: &Self) -> Result<(), OutOfRange<I>> {
         source.clone_into_raw(this)
     }
+
+    /// Reinitializes `target` with the value in `self`. Safe wrapper
+    /// around `clone_into_raw`: `target`'s old value is extracted first
+    /// and only dropped once the new one is successfully in place, so a
+    /// failed clone leaves `target` holding its original value instead
+    /// of a dangling or null one.
+    pub fn clone_into(&self, target: &mut Self) -> Result<(), OutOfRange<I>> {
+        let old = unsafe { P::from_raw(target.get_raw()) };
+        let p = self.with_inner(Clone::clone);
+        match unsafe { Self::set_raw(target, p) } {
+            Ok(()) => {
+                drop(old);
+                Ok(())
+            }
+            Err(e) => {
+                core::mem::forget(old);
+                Err(e)
+            }
+        }
+    }
+
+    /// `Rel::clone_into` with the arguments flipped.
+    /// This is to more closely match `Clone::clone_from`.
+    pub fn clone_from(&mut self, source: &Self) -> Result<(), OutOfRange<I>> {
+        source.clone_into(self)
+    }
 }
 
-impl<P: Pointer, I: PrimInt> Drop for Rel<P, I> {
+impl<P: Pointer, I: NicheInt> Drop for Rel<P, I> {
     fn drop(&mut self) {
         unsafe { P::from_raw(self.get_raw()) };
     }
 }
 
-impl<P: PointerNullable, I: PrimInt> Default for Rel<P, I> {
-    fn default() -> Self {
-        Self::new()
+impl<P: Pointer, I: NicheInt> fmt::Debug for Rel<P, I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Rel").field(&self.get_raw()).finish()
     }
 }
 
-impl<P: PointerNullable, I: PrimInt> Rel<P, I> {
-    /// Initialize an empty instance of this pointer.
-    /// Only allowed if the underlying pointer type supports a
-    /// null value;
-    pub fn new() -> Self {
-        Self {
-            offset: I::zero(),
-            _pd: PhantomData,
-            _pp: PhantomPinned,
-        }
+// `Rel` compares, orders, and hashes by its *resolved* absolute target,
+// matching `core::ptr`'s raw pointers — two `Rel`s at different
+// locations that point at the same object are equal.
+impl<P: Pointer, I: NicheInt> PartialEq for Rel<P, I> {
+    fn eq(&self, other: &Self) -> bool {
+        self.get_raw() == other.get_raw()
     }
+}
 
-    /// Retrieve the smart pointer and replace it with a
-    /// null. Analagous to `Option::take`.
-    pub fn take(&mut self) -> P {
-        let inner = unsafe { P::from_raw(self.get_raw()) };
-        *self = Self::new();
-        inner
+impl<P: Pointer, I: NicheInt> Eq for Rel<P, I> {}
+
+impl<P: Pointer, I: NicheInt> PartialOrd for Rel<P, I> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
-impl<P: PointerNullable + Clone, I: PrimInt> Rel<P, I> {
-    /// Reinitializes `target` with the value in `self`.
-    /// Safe wrapper around `Rel::clone_into_raw` that `Drop`s
-    /// the old value in `target`.
-    pub fn clone_into(&self, target: &mut Self) -> Result<(), OutOfRange<I>> {
-        target.take();
-        unsafe { Self::clone_into_raw(self, target) }
+impl<P: Pointer, I: NicheInt> Ord for Rel<P, I> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.get_raw().cmp(&other.get_raw())
     }
+}
 
-    /// `Rel::clone_into` with the arguments flipped.
-    /// This is to more closely match `Clone::clone_from`.
-    pub fn clone_from(&mut self, source: &Self) -> Result<(), OutOfRange<I>> {
-        source.clone_into(self)
+impl<P: Pointer, I: NicheInt> Hash for Rel<P, I> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.get_raw().hash(state);
     }
 }
 
-impl<P: PointerNonNull + Deref<Target = <P as Pointer>::Target>, I: PrimInt> Deref for Rel<P, I> {
+impl<P: PointerNonNull + Deref<Target = <P as Pointer>::Target>, I: NicheInt> Deref for Rel<P, I> {
     type Target = <P as Pointer>::Target;
     fn deref(&self) -> &Self::Target {
         unsafe { &*self.get_raw() }
     }
 }
 
-impl<P: PointerNonNull + PointerMut + DerefMut<Target = <P as Pointer>::Target>, I: PrimInt>
+impl<P: PointerNonNull + PointerMut + DerefMut<Target = <P as Pointer>::Target>, I: NicheInt>
     DerefMut for Rel<P, I>
 {
     fn deref_mut(&mut self) -> &mut Self::Target {
         unsafe { &mut *self.get_raw_mut() }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn swap_exchanges_targets() {
+        let mut a_slot = MaybeUninit::uninit();
+        let a = RelBox::<i32>::init(&mut a_slot, Box::new(1)).unwrap();
+        let mut b_slot = MaybeUninit::uninit();
+        let b = RelBox::<i32>::init(&mut b_slot, Box::new(2)).unwrap();
+
+        a.swap(b).unwrap();
+
+        assert_eq!(**a, 2);
+        assert_eq!(**b, 1);
+    }
+
+    #[test]
+    fn swap_restores_targets_on_out_of_range_failure() {
+        // An `i8` offset only reaches about ±127 bytes. Separating `a`'s
+        // target (`x`) from `b`'s target (`y`) by a large padding field
+        // guarantees that exchanging `a`'s target for `y` -- the first
+        // thing `swap` attempts -- is out of range, while each `Rel`
+        // can still reach its own nearby original target. That's
+        // exactly the situation the `OutOfRange` recovery path in
+        // `swap` has to undo without leaking or duplicating a target.
+        #[repr(C)]
+        struct Pair {
+            x: i32,
+            a: Rel<*const i32, i8>,
+            _padding: [u8; 256],
+            y: i32,
+            b: Rel<*const i32, i8>,
+        }
+
+        let mut slot = MaybeUninit::<Pair>::uninit();
+        let ptr = slot.as_mut_ptr();
+        unsafe {
+            core::ptr::addr_of_mut!((*ptr).x).write(1);
+            core::ptr::addr_of_mut!((*ptr).y).write(2);
+
+            let a_ptr = core::ptr::addr_of_mut!((*ptr).a);
+            let x_ptr = core::ptr::addr_of!((*ptr).x);
+            Rel::set_raw(a_ptr, x_ptr).unwrap();
+
+            let b_ptr = core::ptr::addr_of_mut!((*ptr).b);
+            let y_ptr = core::ptr::addr_of!((*ptr).y);
+            Rel::set_raw(b_ptr, y_ptr).unwrap();
+        }
+        let mut pair = unsafe { slot.assume_init() };
+
+        assert!(pair.a.swap(&mut pair.b).is_err());
+
+        assert_eq!(pair.a.get_raw(), core::ptr::addr_of!(pair.x));
+        assert_eq!(pair.b.get_raw(), core::ptr::addr_of!(pair.y));
+        assert_eq!(unsafe { *pair.a.get_raw() }, 1);
+        assert_eq!(unsafe { *pair.b.get_raw() }, 2);
+    }
+
+    #[test]
+    fn self_pointer_resolves_to_its_own_address() {
+        // A single-field `repr(C)` struct puts `link` at the same
+        // address as the struct itself, so pointing `link` at `node_ptr`
+        // is a real, in-range self-pointer with a raw delta of 0 — the
+        // exact value `bias` has to steer clear of so `offset` stays
+        // nonzero.
+        #[repr(C)]
+        struct Node {
+            link: Rel<*const Node, isize>,
+        }
+
+        let mut slot = MaybeUninit::<Node>::uninit();
+        let node_ptr = slot.as_mut_ptr();
+        unsafe {
+            let link_ptr = core::ptr::addr_of_mut!((*node_ptr).link);
+            Rel::set_raw(link_ptr, node_ptr as *const Node).unwrap();
+        }
+        let node = unsafe { slot.assume_init() };
+
+        assert_eq!(node.link.get_raw(), &node as *const Node);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn rel_ref_slice_roundtrips_through_get_raw() {
+        let data = [1, 2, 3, 4];
+        let mut slot = MaybeUninit::uninit();
+        let rel = RelRef::<[i32]>::init(&mut slot, &data[..]).unwrap();
+
+        assert_eq!(unsafe { &*rel.get_raw() }, &data[..]);
+        assert_eq!(&**rel, &data[..]);
+    }
+
+    #[cfg(all(feature = "nightly", any(feature = "alloc", feature = "std")))]
+    #[test]
+    fn rel_box_str_roundtrips_through_get_raw() {
+        let mut slot = MaybeUninit::uninit();
+        let boxed: Box<str> = "hello".into();
+        let rel = RelBox::<str>::init(&mut slot, boxed).unwrap();
+
+        assert_eq!(unsafe { &*rel.get_raw() }, "hello");
+        assert_eq!(&**rel, "hello");
+    }
+
+    #[cfg(all(feature = "nightly", any(feature = "alloc", feature = "std")))]
+    #[test]
+    fn rel_box_slice_roundtrips_through_get_raw() {
+        let mut slot = MaybeUninit::uninit();
+        let boxed: Box<[i32]> = Box::from([1, 2, 3]);
+        let rel = RelBox::<[i32]>::init(&mut slot, boxed).unwrap();
+
+        assert_eq!(unsafe { &*rel.get_raw() }, &[1, 2, 3][..]);
+        assert_eq!(&**rel, &[1, 2, 3][..]);
+    }
+
+    #[test]
+    fn equal_targets_compare_equal_and_hash_consistently() {
+        // `a` and `b` live at two independent stack addresses and store
+        // unrelated raw offsets, but both resolve to the same `target`
+        // -- exactly the case a field-wise derive would get wrong.
+        let target = 42i32;
+
+        #[repr(C)]
+        struct Holder {
+            _pad: [u8; 16],
+            link: Rel<*const i32>,
+        }
+
+        let mut a_slot = MaybeUninit::<Holder>::uninit();
+        unsafe {
+            let link = core::ptr::addr_of_mut!((*a_slot.as_mut_ptr()).link);
+            Rel::set_raw(link, &target as *const i32).unwrap();
+        }
+        let a = unsafe { a_slot.assume_init_ref() };
+
+        let mut b_slot = MaybeUninit::<Holder>::uninit();
+        unsafe {
+            let link = core::ptr::addr_of_mut!((*b_slot.as_mut_ptr()).link);
+            Rel::set_raw(link, &target as *const i32).unwrap();
+        }
+        let b = unsafe { b_slot.assume_init_ref() };
+
+        assert_ne!(core::ptr::addr_of!(a.link), core::ptr::addr_of!(b.link));
+        assert_eq!(a.link, b.link);
+        assert_eq!(a.link.cmp(&b.link), Ordering::Equal);
+
+        let mut ha = std::collections::hash_map::DefaultHasher::new();
+        a.link.hash(&mut ha);
+        let mut hb = std::collections::hash_map::DefaultHasher::new();
+        b.link.hash(&mut hb);
+        assert_eq!(ha.finish(), hb.finish());
+    }
+
+    #[test]
+    fn option_rel_is_niche_optimized() {
+        // `offset` is backed by a `NonZero` integer, so the all-zero bit
+        // pattern is free for `Option` to use as its own `None`.
+        assert_eq!(
+            core::mem::size_of::<Option<RelRef<'static, i32>>>(),
+            core::mem::size_of::<RelRef<'static, i32>>(),
+        );
+    }
+}
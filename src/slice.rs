@@ -0,0 +1,285 @@
+//! A relative, position-independent array, built on top of the same
+//! offset arithmetic `Rel` uses. Unlike wrapping `T` elements one by one
+//! in individual `Rel`s, `RelSlice`/`RelSliceMut` store a single base
+//! offset plus an element count, so the whole span can be relocated
+//! (memcpy'd, mmap'd) as one block.
+
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ops::{Deref, Index, IndexMut};
+use core::slice::{self, Iter, IterMut};
+use num_traits::PrimInt;
+
+use crate::OutOfRange;
+
+/// A relative, read-only view over a contiguous run of `T`, analogous
+/// to `&[T]` but storing its base address as an offset from the
+/// `RelSlice` itself rather than an absolute pointer.
+/// As with `Rel`, it is only safe to move this if it stays within the
+/// same block of memory as the slice it points at.
+///
+/// Because the offset is relative to the `RelSlice`'s own address, it
+/// can only be computed once that address is final; a constructor
+/// returning `Self` by value would compute it against a temporary that
+/// is about to move. `RelSlice` is therefore built in place via `init`,
+/// the same pattern `Rel::init` uses.
+///
+/// Safely built only from a shared `&'a [T]`, so there is no way to reach a
+/// `&mut T` through one — mirroring how `Rel`'s own mutable access is
+/// only ever available through a `PointerMut` source. Use
+/// [`RelSliceMut`] when the backing storage is uniquely owned.
+pub struct RelSlice<'a, T, I: PrimInt = isize> {
+    offset: I,
+    len: usize,
+    _marker: PhantomData<&'a [T]>,
+}
+
+impl<'a, T, I: PrimInt> RelSlice<'a, T, I> {
+    /// Initializes a `RelSlice` that is already sitting at its final
+    /// memory location, wrapped in `MaybeUninit` (e.g. a field reached
+    /// through a `builder::RelBuilder`, or a local that is guaranteed
+    /// not to move afterwards). Fails if `ptr`, or the address one past
+    /// the `len`-element span starting at it, doesn't fit in the offset
+    /// range of `I`.
+    ///
+    /// # Safety
+    /// `ptr` must point to `len` initialized `T`s that stay valid, and
+    /// are not mutated, for all of `'a`.
+    pub unsafe fn init(
+        this: &mut MaybeUninit<Self>,
+        ptr: *const T,
+        len: usize,
+    ) -> Result<&mut Self, OutOfRange<I>> {
+        unsafe {
+            Self::set_raw(this.as_mut_ptr(), ptr, len)?;
+            Ok(this.assume_init_mut())
+        }
+    }
+
+    /// `init`, taking a shared slice directly rather than a raw pointer
+    /// and length.
+    pub fn init_from_slice<'b>(
+        this: &'b mut MaybeUninit<Self>,
+        slice: &'a [T],
+    ) -> Result<&'b mut Self, OutOfRange<I>> {
+        unsafe { Self::init(this, slice.as_ptr(), slice.len()) }
+    }
+
+    /// Initializes the `RelSlice` at `this`, which must already be at
+    /// its final memory address.
+    ///
+    /// # Safety
+    /// Assumes `this` is valid for writes and is not yet initialized
+    /// (or may be leaked if it already contains a value), and that it
+    /// will not move for as long as the resulting `RelSlice` is used.
+    pub unsafe fn set_raw(
+        this: *mut Self,
+        ptr: *const T,
+        len: usize,
+    ) -> Result<(), OutOfRange<I>> {
+        let offset = Self::offset_to(this, ptr)?;
+        // The end of the span must be reachable too, not just the start,
+        // or elements past it could silently fall outside `I`'s range.
+        Self::offset_to(this, ptr.wrapping_add(len))?;
+        // `*this = Self { .. }` would run `drop_in_place(this)` on
+        // whatever was already there first — harmless today since
+        // `RelSlice` has no `Drop` glue, but a trap waiting for the day
+        // it grows a dropping field. `ptr::write` never reads or drops
+        // the old contents, matching `Rel::set_raw`.
+        core::ptr::write(
+            this,
+            Self {
+                offset,
+                len,
+                _marker: PhantomData,
+            },
+        );
+        Ok(())
+    }
+
+    fn offset_to(this: *const Self, target: *const T) -> Result<I, OutOfRange<I>> {
+        let from = this as *const u8;
+        let to = target as *const u8;
+        let offset = (to as isize).wrapping_sub(from as isize);
+        I::from(offset).ok_or_else(|| OutOfRange::new(offset))
+    }
+
+    fn elem_ptr(&self, index: usize) -> *const T {
+        let offset = self.offset.to_isize().unwrap();
+        let base = (self as *const Self as *const u8).wrapping_offset(offset) as *const T;
+        base.wrapping_add(index)
+    }
+
+    /// The number of elements in this slice.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether this slice contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get a reference to the element at `index`, or `None` if it's out
+    /// of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len {
+            Some(unsafe { &*self.elem_ptr(index) })
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over references to the elements of this slice.
+    pub fn iter(&self) -> Iter<'_, T> {
+        unsafe { slice::from_raw_parts(self.elem_ptr(0), self.len) }.iter()
+    }
+}
+
+impl<'a, T, I: PrimInt> Index<usize> for RelSlice<'a, T, I> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+/// A relative, uniquely-owning view over a contiguous run of `T`,
+/// analogous to `&mut [T]`. Only safely buildable from a `&'a mut [T]`, so
+/// (unlike [`RelSlice`]) it can soundly hand out `&mut T`s — encoding
+/// the mutability of the backing storage in the constructor used,
+/// exactly the way `Rel`'s own `PointerMut`-gated access does.
+///
+/// Derefs to `RelSlice` for the shared-access API (`len`, `get`,
+/// `iter`, `Index`); only the mutable-access API lives directly on
+/// `RelSliceMut`.
+#[repr(transparent)]
+pub struct RelSliceMut<'a, T, I: PrimInt = isize> {
+    inner: RelSlice<'a, T, I>,
+    // Forces invariance in both `'a` and `T`, matching `&'a mut [T]` —
+    // `RelSlice`'s own marker is covariant, which is wrong for a type
+    // that hands out `&mut T`.
+    _invariant: PhantomData<&'a mut [T]>,
+}
+
+impl<'a, T, I: PrimInt> RelSliceMut<'a, T, I> {
+    /// Initializes a `RelSliceMut` that is already sitting at its final
+    /// memory location, wrapped in `MaybeUninit`. See `RelSlice::init`.
+    ///
+    /// # Safety
+    /// `ptr` must point to `len` initialized `T`s that stay valid, and
+    /// are not accessed through anything else, for all of `'a`.
+    pub unsafe fn init(
+        this: &mut MaybeUninit<Self>,
+        ptr: *mut T,
+        len: usize,
+    ) -> Result<&mut Self, OutOfRange<I>> {
+        unsafe {
+            Self::set_raw(this.as_mut_ptr(), ptr, len)?;
+            Ok(this.assume_init_mut())
+        }
+    }
+
+    /// `init`, taking a mutable slice directly rather than a raw pointer
+    /// and length.
+    pub fn init_from_mut_slice<'b>(
+        this: &'b mut MaybeUninit<Self>,
+        slice: &'a mut [T],
+    ) -> Result<&'b mut Self, OutOfRange<I>> {
+        unsafe { Self::init(this, slice.as_mut_ptr(), slice.len()) }
+    }
+
+    /// Initializes the `RelSliceMut` at `this`, which must already be
+    /// at its final memory address.
+    ///
+    /// # Safety
+    /// Same contract as `RelSlice::set_raw`.
+    pub unsafe fn set_raw(this: *mut Self, ptr: *mut T, len: usize) -> Result<(), OutOfRange<I>> {
+        // `RelSliceMut` is `repr(transparent)` over `RelSlice`, so a
+        // pointer to one is a valid pointer to the other; the
+        // `_invariant` marker is a ZST and needs no initialization.
+        RelSlice::set_raw(this as *mut RelSlice<'a, T, I>, ptr as *const T, len)
+    }
+
+    fn elem_ptr_mut(&mut self, index: usize) -> *mut T {
+        self.inner.elem_ptr(index) as *mut T
+    }
+
+    /// Get a mutable reference to the element at `index`, or `None` if
+    /// it's out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.inner.len {
+            Some(unsafe { &mut *self.elem_ptr_mut(index) })
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over mutable references to the elements of this slice.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let len = self.inner.len;
+        unsafe { slice::from_raw_parts_mut(self.elem_ptr_mut(0), len) }.iter_mut()
+    }
+}
+
+impl<'a, T, I: PrimInt> Deref for RelSliceMut<'a, T, I> {
+    type Target = RelSlice<'a, T, I>;
+
+    fn deref(&self) -> &RelSlice<'a, T, I> {
+        &self.inner
+    }
+}
+
+impl<'a, T, I: PrimInt> Index<usize> for RelSliceMut<'a, T, I> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        self.inner.index(index)
+    }
+}
+
+impl<'a, T, I: PrimInt> IndexMut<usize> for RelSliceMut<'a, T, I> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        self.get_mut(index).expect("index out of bounds")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_elements_after_construction() {
+        let data = [10, 20, 30];
+        let mut slot = MaybeUninit::uninit();
+        let view = RelSlice::<'_, i32>::init_from_slice(&mut slot, &data).unwrap();
+
+        assert_eq!(view.len(), 3);
+        assert_eq!(view[0], 10);
+        assert_eq!(view[1], 20);
+        assert_eq!(view[2], 30);
+
+        let mut iter = view.iter();
+        assert_eq!(iter.next(), Some(&10));
+        assert_eq!(iter.next(), Some(&20));
+        assert_eq!(iter.next(), Some(&30));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn mut_view_writes_through_to_the_source_slice() {
+        let mut data = [10, 20, 30];
+        let mut slot = MaybeUninit::uninit();
+        let view = RelSliceMut::<'_, i32>::init_from_mut_slice(&mut slot, &mut data).unwrap();
+
+        *view.get_mut(1).unwrap() = 99;
+        for elem in view.iter_mut() {
+            *elem += 1;
+        }
+
+        assert_eq!(view[0], 11);
+        assert_eq!(view[1], 100);
+        assert_eq!(view[2], 31);
+        assert_eq!(data, [11, 100, 31]);
+    }
+}
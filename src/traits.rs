@@ -4,12 +4,21 @@
 //! is allowed or disallowed.
 
 use core::ptr::{self, NonNull};
+#[cfg(feature = "nightly")]
+use core::ptr::Pointee;
 
 /// The base `Pointer` trait. This is implemented for all pointer types usable with this library.
 /// It plays a similar role to `Deref`, but uses raw pointers and can be used to reconstruct
 /// the original pointer.
 pub trait Pointer: Sized {
     /// The type this pointer is pointing at.
+    /// `?Sized` targets (e.g. `[T]`, `str`) are only available with the
+    /// `nightly` feature, since reconstructing a fat pointer relies on
+    /// the unstable `ptr_metadata` APIs.
+    #[cfg(feature = "nightly")]
+    type Target: ?Sized;
+    /// The type this pointer is pointing at.
+    #[cfg(not(feature = "nightly"))]
     type Target;
 
     /// Convert this smart pointer into a raw pointer
@@ -33,6 +42,45 @@ pub trait PointerMut: Pointer {
     }
 }
 
+/// Marker for pointer metadata that stays meaningful after its pointee
+/// is relocated to a different address — and potentially a different
+/// process or address space entirely, which is this crate's whole
+/// reason for existing. This covers every metadata shape the `nightly`
+/// `?Sized` support otherwise hands out (`()` for `Sized` targets,
+/// `usize` for slice/`str` lengths), but deliberately excludes a `dyn
+/// Trait`'s `DynMetadata`: a vtable pointer is an absolute address
+/// belonging to the process that built it, so it becomes garbage (and
+/// a virtual call through it is UB) the moment the pointee is
+/// relocated elsewhere. Sealed: this can't be implemented outside this
+/// crate, so `Pointer for &dyn SomeTrait`-style impls are rejected at
+/// the impl itself rather than relying on documentation alone.
+///
+/// ```compile_fail
+/// #![feature(ptr_metadata)]
+/// trait Greet { fn hi(&self); }
+/// impl Greet for i32 { fn hi(&self) {} }
+///
+/// // `&dyn Greet`'s metadata is a vtable pointer, which never
+/// // implements `RelocatableMetadata`, so this has no `Pointer` impl
+/// // to satisfy the bound and fails to compile:
+/// fn needs_pointer<P: rel::traits::Pointer>() {}
+/// needs_pointer::<&dyn Greet>();
+/// ```
+#[cfg(feature = "nightly")]
+pub trait RelocatableMetadata: sealed::Sealed {}
+
+#[cfg(feature = "nightly")]
+impl RelocatableMetadata for () {}
+#[cfg(feature = "nightly")]
+impl RelocatableMetadata for usize {}
+
+#[cfg(feature = "nightly")]
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for () {}
+    impl Sealed for usize {}
+}
+
 /// Denotes that this pointer will never be null.
 pub trait PointerNonNull: Pointer {
     /// A wrapper around `Pointer::into_raw` to obtain a `NonNull` pointer.
@@ -41,15 +89,6 @@ pub trait PointerNonNull: Pointer {
     }
 }
 
-/// The oposite of `PointerNonNull`.
-/// Denotes that this pointer possesses a null state where it contains nothing.
-pub trait PointerNullable: Pointer {
-    /// Obtain an empty instance of this pointer.
-    fn get_null() -> Self {
-        unsafe { Self::from_raw(ptr::null()) }
-    }
-}
-
 /// Raw Pointers
 
 impl<T> Pointer for *const T {
@@ -75,12 +114,26 @@ impl<T> Pointer for *mut T {
 }
 
 impl<T> PointerMut for *mut T {}
-impl<T> PointerNullable for *const T {}
-impl<T> PointerNullable for *mut T {}
 
 /// References
 
-impl<'a, T> Pointer for &T {
+#[cfg(feature = "nightly")]
+impl<'a, T: ?Sized> Pointer for &'a T
+where
+    <T as Pointee>::Metadata: RelocatableMetadata,
+{
+    type Target = T;
+
+    fn into_raw(self) -> *const T {
+        self as *const T
+    }
+    unsafe fn from_raw(p: *const T) -> Self {
+        &*p
+    }
+}
+
+#[cfg(not(feature = "nightly"))]
+impl<'a, T> Pointer for &'a T {
     type Target = T;
 
     fn into_raw(self) -> *const T {
@@ -91,7 +144,11 @@ impl<'a, T> Pointer for &T {
     }
 }
 
-impl<'a, T> Pointer for &mut T {
+#[cfg(feature = "nightly")]
+impl<'a, T: ?Sized> Pointer for &'a mut T
+where
+    <T as Pointee>::Metadata: RelocatableMetadata,
+{
     type Target = T;
 
     fn into_raw(self) -> *const T {
@@ -102,15 +159,50 @@ impl<'a, T> Pointer for &mut T {
     }
 }
 
-impl<'a, T> PointerMut for &mut T {}
+#[cfg(not(feature = "nightly"))]
+impl<'a, T> Pointer for &'a mut T {
+    type Target = T;
 
-impl<'a, T> PointerNonNull for &T {
+    fn into_raw(self) -> *const T {
+        self as *const T
+    }
+    unsafe fn from_raw(p: *const T) -> Self {
+        &mut *(p as *mut T)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<'a, T: ?Sized> PointerMut for &'a mut T where <T as Pointee>::Metadata: RelocatableMetadata {}
+#[cfg(not(feature = "nightly"))]
+impl<'a, T> PointerMut for &'a mut T {}
+
+#[cfg(feature = "nightly")]
+impl<'a, T: ?Sized> PointerNonNull for &'a T
+where
+    <T as Pointee>::Metadata: RelocatableMetadata,
+{
+    fn into_raw_nonnull(self) -> NonNull<T> {
+        self.into()
+    }
+}
+#[cfg(not(feature = "nightly"))]
+impl<'a, T> PointerNonNull for &'a T {
     fn into_raw_nonnull(self) -> NonNull<T> {
         self.into()
     }
 }
 
-impl<'a, T> PointerNonNull for &mut T {
+#[cfg(feature = "nightly")]
+impl<'a, T: ?Sized> PointerNonNull for &'a mut T
+where
+    <T as Pointee>::Metadata: RelocatableMetadata,
+{
+    fn into_raw_nonnull(self) -> NonNull<T> {
+        self.into()
+    }
+}
+#[cfg(not(feature = "nightly"))]
+impl<'a, T> PointerNonNull for &'a mut T {
     fn into_raw_nonnull(self) -> NonNull<T> {
         self.into()
     }
@@ -139,6 +231,29 @@ impl<T> PointerNonNull for NonNull<T> {
 
 /// Optional pointer
 
+#[cfg(feature = "nightly")]
+impl<P: PointerNonNull> Pointer for Option<P>
+where
+    <P::Target as Pointee>::Metadata: Default,
+{
+    type Target = P::Target;
+
+    fn into_raw(self) -> *const P::Target {
+        self.map_or_else(
+            || ptr::from_raw_parts(ptr::null(), Default::default()),
+            P::into_raw,
+        )
+    }
+    unsafe fn from_raw(p: *const P::Target) -> Self {
+        if p.is_null() {
+            None
+        } else {
+            Some(P::from_raw(p))
+        }
+    }
+}
+
+#[cfg(not(feature = "nightly"))]
 impl<P: PointerNonNull> Pointer for Option<P> {
     type Target = P::Target;
 
@@ -155,11 +270,6 @@ impl<P: PointerNonNull> Pointer for Option<P> {
 }
 
 impl<P: PointerNonNull + PointerMut> PointerMut for Option<P> {}
-impl<P: PointerNonNull> PointerNullable for Option<P> {
-    fn get_null() -> Self {
-        None
-    }
-}
 
 #[cfg(all(
     any(feature = "alloc", feature = "std"),
@@ -174,6 +284,22 @@ mod _alloc {
     /// Box
     use alloc::boxed::Box;
 
+    #[cfg(feature = "nightly")]
+    impl<T: ?Sized> Pointer for Box<T>
+    where
+        <T as Pointee>::Metadata: RelocatableMetadata,
+    {
+        type Target = T;
+
+        fn into_raw(self) -> *const T {
+            Box::into_raw(self) as *const T
+        }
+        unsafe fn from_raw(p: *const T) -> Self {
+            Box::from_raw(p as *mut T)
+        }
+    }
+
+    #[cfg(not(feature = "nightly"))]
     impl<T> Pointer for Box<T> {
         type Target = T;
 
@@ -185,14 +311,22 @@ mod _alloc {
         }
     }
 
+    #[cfg(feature = "nightly")]
+    impl<T: ?Sized> PointerMut for Box<T> where <T as Pointee>::Metadata: RelocatableMetadata {}
+    #[cfg(not(feature = "nightly"))]
     impl<T> PointerMut for Box<T> {}
 
-    impl<T> PointerNonNull for Box<T> {
-        #[cfg(feature = "nightly")]
+    #[cfg(feature = "nightly")]
+    impl<T: ?Sized> PointerNonNull for Box<T>
+    where
+        <T as Pointee>::Metadata: RelocatableMetadata,
+    {
         fn into_raw_nonnull(self) -> NonNull<T> {
             Box::into_raw_non_null(self)
         }
     }
+    #[cfg(not(feature = "nightly"))]
+    impl<T> PointerNonNull for Box<T> {}
 
     /// Rc
     use alloc::rc::Rc;